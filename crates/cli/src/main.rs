@@ -2,7 +2,15 @@
 
 use std::{fs::File, io::Write, path::PathBuf};
 
+use chrono::TimeZone;
 use clap::Parser;
+use uo2ics_core::{buildings, conflicts::Period, html::Privacy, institution::UOTTAWA};
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Format {
+	Html,
+	Ics,
+}
 
 #[derive(Parser)]
 struct Args {
@@ -10,17 +18,83 @@ struct Args {
 	path: Option<PathBuf>,
 	#[clap(short, long, value_hint = clap::ValueHint::FilePath)]
 	output: Option<PathBuf>,
+	/// Excluded date ranges (reading week, holidays), as a file path or an
+	/// inline `YYYY-MM-DD..YYYY-MM-DD`/`YYYY-MM-DD` list
+	#[clap(short = 'x', long)]
+	exclude: Option<String>,
+	/// CSV file mapping building codes to latitude/longitude, used to add a
+	/// `GEO` property to events whose location matches a known building
+	#[clap(long, value_hint = clap::ValueHint::FilePath)]
+	buildings: Option<PathBuf>,
+	#[clap(short, long, value_enum, default_value = "ics")]
+	format: Format,
+	#[clap(long, value_enum, default_value = "private")]
+	privacy: Privacy,
+	/// Existing .ics files to check the parsed schedule against for
+	/// conflicts, instead of exporting a calendar
+	#[clap(long, value_hint = clap::ValueHint::FilePath)]
+	conflicts: Vec<PathBuf>,
+	#[clap(long, value_enum, default_value = "week")]
+	period: Period,
 }
 
 fn main() {
 	let args = Args::parse();
-	let courses = uo2ics_core::course::parse_from_file(args.path);
-	let calendar = uo2ics_core::create_calendar(courses);
+	let profile = UOTTAWA;
+	let exclusions = args
+		.exclude
+		.as_deref()
+		.map(uo2ics_core::exclusions::load_exclusions)
+		.unwrap_or_default();
+	let buildings = args
+		.buildings
+		.map(buildings::load_buildings)
+		.unwrap_or_default();
+	let courses = uo2ics_core::course::parse_from_file(args.path, &profile);
+
+	if !args.conflicts.is_empty() {
+		let calendar = uo2ics_core::create_calendar(courses, &exclusions, &buildings, &profile);
+		let today = chrono::Utc::now().with_timezone(&profile.timezone).date_naive();
+		let (window_start, window_end) = args.period.window(today, profile.timezone);
+
+		let mut occurrences = uo2ics_core::conflicts::expand_calendar(
+			&calendar,
+			profile.timezone,
+			window_start,
+			window_end,
+		);
+
+		for path in &args.conflicts {
+			let other = uo2ics_core::conflicts::load_calendar(path);
+			occurrences.extend(uo2ics_core::conflicts::expand_calendar(
+				&other,
+				profile.timezone,
+				window_start,
+				window_end,
+			));
+		}
+
+		for (a, b) in uo2ics_core::conflicts::find_conflicts(&mut occurrences) {
+			println!(
+				"conflict: \"{}\" ({} - {}) overlaps \"{}\" ({} - {})",
+				a.summary, a.start, a.end, b.summary, b.start, b.end
+			);
+		}
+
+		return;
+	}
+
+	let output_text = match args.format {
+		Format::Ics => {
+			uo2ics_core::create_calendar(courses, &exclusions, &buildings, &profile).to_string()
+		}
+		Format::Html => uo2ics_core::html::create_html(courses, args.privacy),
+	};
 
 	if let Some(output) = args.output {
 		let mut file = File::create(output).unwrap();
-		write!(&mut file, "{calendar}").unwrap();
+		write!(&mut file, "{output_text}").unwrap();
 	} else {
-		write!(&mut std::io::stdout(), "{calendar}").unwrap();
+		write!(&mut std::io::stdout(), "{output_text}").unwrap();
 	}
 }
@@ -0,0 +1,88 @@
+use std::{collections::HashMap, path::Path};
+
+/// A single building's coordinates, as loaded from the registry CSV.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Building {
+	pub code: String,
+	pub latitude: f64,
+	pub longitude: f64,
+}
+
+impl Building {
+	/// Render as an RFC 5545 `GEO` property value, e.g. `45.4231;-75.6831`.
+	#[must_use]
+	pub fn geo(&self) -> String {
+		format!("{};{}", self.latitude, self.longitude)
+	}
+}
+
+/// A lookup from building code (e.g. `"STE"`) to its coordinates.
+#[derive(Debug, Clone, Default)]
+pub struct BuildingRegistry(HashMap<String, Building>);
+
+impl BuildingRegistry {
+	#[must_use]
+	pub fn get(&self, code: &str) -> Option<&Building> {
+		self.0.get(code)
+	}
+
+	/// Look up a building from a scraped class address such as `"STE 0129"`,
+	/// whose leading whitespace-separated token is the building code the
+	/// registry is keyed on.
+	#[must_use]
+	pub fn get_by_address(&self, address: &str) -> Option<&Building> {
+		self.get(address.split_whitespace().next()?)
+	}
+}
+
+/// Load a building registry from a CSV file with `code,latitude,longitude`
+/// columns. Returns an empty registry (never an error) if the file can't be
+/// read or parsed, so an unknown or missing `--buildings` argument just
+/// falls back to text-only locations.
+#[must_use]
+pub fn load_buildings<P: AsRef<Path>>(path: P) -> BuildingRegistry {
+	let Ok(mut reader) = csv::Reader::from_path(path) else {
+		return BuildingRegistry::default();
+	};
+
+	let buildings = reader
+		.deserialize::<Building>()
+		.filter_map(Result::ok)
+		.map(|building| (building.code.clone(), building))
+		.collect();
+
+	BuildingRegistry(buildings)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Building, BuildingRegistry};
+
+	fn registry() -> BuildingRegistry {
+		BuildingRegistry(std::collections::HashMap::from([(
+			"STE".to_string(),
+			Building {
+				code: "STE".to_string(),
+				latitude: 45.4231,
+				longitude: -75.6831,
+			},
+		)]))
+	}
+
+	#[test]
+	fn get_by_address_matches_the_leading_building_code() {
+		let registry = registry();
+
+		let building = registry.get_by_address("STE 0129").unwrap();
+
+		assert_eq!(building.code, "STE");
+		assert_eq!(building.geo(), "45.4231;-75.6831");
+	}
+
+	#[test]
+	fn get_by_address_misses_an_unknown_code() {
+		let registry = registry();
+
+		assert!(registry.get_by_address("MRN 109").is_none());
+	}
+}
@@ -0,0 +1,292 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+use icalendar::{Calendar, CalendarComponent, CalendarDateTime, Component, DatePerhapsTime, EventLike};
+
+use crate::resolve_local;
+
+/// A single concrete occurrence of a (possibly recurring) event, expanded
+/// from its `DTSTART`/`RRULE`/`EXDATE`.
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+	pub summary: String,
+	pub start: DateTime<Tz>,
+	pub end: DateTime<Tz>,
+}
+
+/// The `day`/`week`/`month` windowing used to scope a conflict check.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Period {
+	Day,
+	Week,
+	Month,
+}
+
+impl Period {
+	/// The `[start, end)` window of this period beginning on `date`, in `tz`.
+	#[must_use]
+	pub fn window(self, date: NaiveDate, tz: Tz) -> (DateTime<Tz>, DateTime<Tz>) {
+		let start = tz
+			.from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+			.unwrap();
+		let end = match self {
+			Self::Day => start + Duration::days(1),
+			Self::Week => start + Duration::weeks(1),
+			Self::Month => start + Duration::days(30),
+		};
+
+		(start, end)
+	}
+}
+
+/// Parse an existing `.ics` file into a [`Calendar`].
+///
+/// # Panics
+///
+/// Panics if the file can't be read or isn't valid iCalendar data.
+#[must_use]
+pub fn load_calendar<P: AsRef<Path>>(path: P) -> Calendar {
+	std::fs::read_to_string(path).unwrap().parse().unwrap()
+}
+
+fn to_local(date_time: &DatePerhapsTime, tz: Tz) -> Option<DateTime<Tz>> {
+	match date_time {
+		DatePerhapsTime::DateTime(CalendarDateTime::WithTimezone { date_time, tzid }) => {
+			let event_tz = Tz::from_str(tzid).unwrap_or(tz);
+
+			event_tz
+				.from_local_datetime(date_time)
+				.single()
+				.map(|local| local.with_timezone(&tz))
+		}
+		DatePerhapsTime::DateTime(CalendarDateTime::Floating(date_time)) => tz.from_local_datetime(date_time).single(),
+		DatePerhapsTime::DateTime(CalendarDateTime::Utc(date_time)) => Some(date_time.with_timezone(&tz)),
+		DatePerhapsTime::Date(date) => tz.from_local_datetime(&date.and_hms_opt(0, 0, 0)?).single(),
+	}
+}
+
+/// The excluded occurrence dates named by an `EXDATE` property value, which
+/// is a comma-separated list of local `YYYYMMDDTHHMMSS` timestamps.
+fn parse_exdates(raw: Option<&str>) -> Vec<NaiveDateTime> {
+	raw.into_iter()
+		.flat_map(|value| value.split(','))
+		.filter_map(|value| NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok())
+		.collect()
+}
+
+/// The `FREQ` an imported `RRULE` steps at. `create_calendar` only ever
+/// emits `Weekly`, but a `.ics` file read back via `--conflicts` may come
+/// from an unrelated personal calendar with any of these.
+#[derive(Debug, Clone, Copy)]
+enum Freq {
+	Daily,
+	Weekly,
+	Monthly,
+	Yearly,
+}
+
+/// The `FREQ` of an `RRULE` property value, defaulting to [`Freq::Weekly`]
+/// (`create_calendar`'s own convention) if absent or unrecognized.
+fn parse_freq(raw: Option<&str>) -> Freq {
+	match raw.and_then(|raw| raw.split("FREQ=").nth(1)).and_then(|freq| freq.split(';').next()) {
+		Some("DAILY") => Freq::Daily,
+		Some("MONTHLY") => Freq::Monthly,
+		Some("YEARLY") => Freq::Yearly,
+		_ => Freq::Weekly,
+	}
+}
+
+/// The `UNTIL` bound of an `RRULE` property value, if present.
+fn parse_until(raw: Option<&str>, tz: Tz) -> Option<DateTime<Tz>> {
+	let until = raw?.split("UNTIL=").nth(1)?.split(';').next()?;
+	let until = NaiveDateTime::parse_from_str(until, "%Y%m%dT%H%M%SZ").ok()?;
+
+	Some(tz.from_utc_datetime(&until))
+}
+
+/// The `COUNT` bound of an `RRULE` property value, if present.
+fn parse_count(raw: Option<&str>) -> Option<usize> {
+	raw?.split("COUNT=").nth(1)?.split(';').next()?.parse().ok()
+}
+
+/// Advance `date_time` by one occurrence of `freq`, in local time, so the
+/// wall-clock time of day is preserved rather than drifting with the
+/// absolute instant.
+fn step(freq: Freq, date_time: NaiveDateTime) -> Option<NaiveDateTime> {
+	match freq {
+		Freq::Daily => Some(date_time + Duration::days(1)),
+		Freq::Weekly => Some(date_time + Duration::days(7)),
+		Freq::Monthly => date_time
+			.date()
+			.checked_add_months(chrono::Months::new(1))
+			.map(|date| date.and_time(date_time.time())),
+		Freq::Yearly => date_time
+			.date()
+			.checked_add_months(chrono::Months::new(12))
+			.map(|date| date.and_time(date_time.time())),
+	}
+}
+
+/// Every occurrence of an imported event, from its first meeting up to
+/// and including `until`, stepping at `freq` and capped at `count`
+/// occurrences if the `RRULE` named one. Local times that are ambiguous
+/// or don't exist around a daylight-saving transition are resolved via
+/// [`resolve_local`] rather than truncating the sequence.
+fn rrule_occurrences(
+	start: DateTime<Tz>,
+	until: DateTime<Tz>,
+	freq: Freq,
+	count: Option<usize>,
+) -> impl Iterator<Item = DateTime<Tz>> {
+	std::iter::successors(Some(start), move |prev| {
+		let tz = prev.timezone();
+		let next = resolve_local(tz, step(freq, prev.naive_local())?)?;
+
+		(next <= until).then_some(next)
+	})
+	.take(count.unwrap_or(usize::MAX))
+}
+
+/// Expand every `VEVENT` in `calendar` into its concrete occurrences within
+/// `[window_start, window_end)`, following its `RRULE` and `EXDATE`. `tz`
+/// is the timezone to interpret floating and date-only events in.
+///
+/// A non-recurring event (no `RRULE`) only ever yields its own `DTSTART`.
+/// A recurring one with no `UNTIL` - an open-ended or `COUNT`-bounded
+/// `RRULE`, common in real personal calendars - is expanded up to
+/// `window_end` instead of collapsing to a single occurrence at `DTSTART`,
+/// so it isn't silently dropped from conflict detection just because it
+/// was first scheduled outside the query window.
+#[must_use]
+pub fn expand_calendar(
+	calendar: &Calendar,
+	tz: Tz,
+	window_start: DateTime<Tz>,
+	window_end: DateTime<Tz>,
+) -> Vec<Occurrence> {
+	calendar
+		.components
+		.iter()
+		.filter_map(|component| match component {
+			CalendarComponent::Event(event) => Some(event),
+			_ => None,
+		})
+		.filter_map(|event| {
+			let start = to_local(&event.get_start()?, tz)?;
+			let end = to_local(&event.get_end()?, tz)?;
+			let rrule = event.property_value("RRULE");
+			let until = match rrule {
+				Some(_) => parse_until(rrule, tz).unwrap_or(window_end),
+				None => start,
+			};
+			let freq = parse_freq(rrule);
+			let count = parse_count(rrule);
+			let exdates = parse_exdates(event.property_value("EXDATE"));
+			let summary = event.get_summary().unwrap_or_default().to_string();
+
+			Some(
+				rrule_occurrences(start, until, freq, count)
+					.filter(move |occurrence| !exdates.contains(&occurrence.naive_local()))
+					.map(move |occurrence_start| Occurrence {
+						summary: summary.clone(),
+						start: occurrence_start,
+						end: occurrence_start + (end - start),
+					})
+					.filter(move |occurrence| {
+						occurrence.start >= window_start && occurrence.start < window_end
+					})
+					.collect::<Vec<_>>(),
+			)
+		})
+		.flatten()
+		.collect()
+}
+
+/// Sort `occurrences` by start time and return every pair whose intervals
+/// overlap, not just adjacent ones, so a long event that spans several
+/// shorter ones is flagged against each of them.
+#[must_use]
+pub fn find_conflicts(occurrences: &mut [Occurrence]) -> Vec<(Occurrence, Occurrence)> {
+	occurrences.sort_by_key(|occurrence| occurrence.start);
+
+	let mut conflicts = Vec::new();
+
+	for (index, occurrence) in occurrences.iter().enumerate() {
+		for other in &occurrences[index + 1..] {
+			if other.start >= occurrence.end {
+				break;
+			}
+
+			conflicts.push((occurrence.clone(), other.clone()));
+		}
+	}
+
+	conflicts
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Datelike, TimeZone};
+	use chrono_tz::America::Toronto;
+	use icalendar::Event;
+
+	use super::{expand_calendar, find_conflicts, Calendar, CalendarDateTime, Component, EventLike, Occurrence};
+
+	fn occurrence(summary: &str, start: (u32, u32), end: (u32, u32)) -> Occurrence {
+		Occurrence {
+			summary: summary.to_string(),
+			start: Toronto
+				.with_ymd_and_hms(2026, 1, 5, start.0, start.1, 0)
+				.unwrap(),
+			end: Toronto.with_ymd_and_hms(2026, 1, 5, end.0, end.1, 0).unwrap(),
+		}
+	}
+
+	#[test]
+	fn find_conflicts_flags_overlapping_occurrences() {
+		let mut occurrences = vec![
+			occurrence("A", (9, 0), (10, 30)),
+			occurrence("B", (10, 0), (11, 0)),
+			occurrence("C", (13, 0), (14, 0)),
+		];
+
+		let conflicts = find_conflicts(&mut occurrences);
+
+		assert_eq!(conflicts.len(), 1);
+		assert_eq!(conflicts[0].0.summary, "A");
+		assert_eq!(conflicts[0].1.summary, "B");
+	}
+
+	/// A `COUNT`-bounded, non-weekly `RRULE` whose `DTSTART` is before the
+	/// query window must still be expanded into the window, instead of
+	/// collapsing to its single original (out-of-window) occurrence.
+	#[test]
+	fn expand_calendar_steps_count_based_daily_rrule_into_window() {
+		let mut calendar = Calendar::new();
+		let mut event = Event::new();
+
+		event
+			.summary("Daily personal event")
+			.starts(CalendarDateTime::WithTimezone {
+				date_time: Toronto.with_ymd_and_hms(2026, 1, 1, 9, 0, 0).unwrap().naive_local(),
+				tzid: Toronto.name().to_string(),
+			})
+			.ends(CalendarDateTime::WithTimezone {
+				date_time: Toronto.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap().naive_local(),
+				tzid: Toronto.name().to_string(),
+			})
+			.add_property("RRULE", "FREQ=DAILY;COUNT=10");
+
+		calendar.push(event);
+
+		let window_start = Toronto.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap();
+		let window_end = Toronto.with_ymd_and_hms(2026, 1, 6, 0, 0, 0).unwrap();
+
+		let occurrences = expand_calendar(&calendar, Toronto, window_start, window_end);
+
+		assert_eq!(occurrences.len(), 1);
+		assert_eq!(occurrences[0].start.day(), 5);
+	}
+}
@@ -1,13 +1,14 @@
 use std::{fmt, fs::File, path::Path, str::FromStr};
 
-use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike};
 use chrono_tz::Tz;
 use select::{
 	document::Document,
 	predicate::{self, Name},
 };
 
-use crate::TZ;
+use crate::institution::InstitutionProfile;
+use crate::resolve_local;
 
 #[derive(Debug)]
 pub enum Status {
@@ -102,6 +103,9 @@ pub struct Class {
 	pub location: String,
 	pub address: String,
 	pub instructor: String,
+	// PeopleSoft's class roster never exposes an email, so this stays
+	// `None` until it's backfilled from a directory lookup elsewhere.
+	pub instructor_email: Option<String>,
 
 	pub end: DateTime<Tz>,
 }
@@ -153,32 +157,28 @@ fn parse_time(s: &str) -> Result<(u8, u8), ()> {
 	Ok((hour, minute))
 }
 
-const WEEKDAYS: [&str; 5] = ["Mo", "Tu", "We", "Th", "Fr"];
-
-impl FromStr for DateTimeRangeRaw {
-	type Err = ();
-
-	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let mut parts = s.splitn(2, ' ');
-		// day of week. 0 = monday
-		let day = parts
-			.next()
-			.ok_or(())
-			.and_then(|d| WEEKDAYS.iter().position(|&weekday| weekday == d).ok_or(()))?;
-		// X:XXAM/PM - X:XXAM/PM
-		let mut time = parts.next().ok_or(())?.split(" - ");
-		let start = time.next().ok_or(())?;
-		let end = time.next().ok_or(())?;
-
-		let start = parse_time(start)?;
-		let end = parse_time(end)?;
-
-		Ok(Self {
-			start,
-			end,
-			weekday: u8::try_from(day).unwrap(),
-		})
-	}
+/// Parse a `"{weekday} {start} - {end}"` cell, e.g. `"Mo 10:00AM - 11:20AM"`,
+/// against the institution's weekday labels.
+fn parse_time_range(s: &str, weekdays: &[&str]) -> Result<DateTimeRangeRaw, ()> {
+	let mut parts = s.splitn(2, ' ');
+	// day of week. 0 = monday
+	let day = parts
+		.next()
+		.ok_or(())
+		.and_then(|d| weekdays.iter().position(|&weekday| weekday == d).ok_or(()))?;
+	// X:XXAM/PM - X:XXAM/PM
+	let mut time = parts.next().ok_or(())?.split(" - ");
+	let start = time.next().ok_or(())?;
+	let end = time.next().ok_or(())?;
+
+	let start = parse_time(start)?;
+	let end = parse_time(end)?;
+
+	Ok(DateTimeRangeRaw {
+		start,
+		end,
+		weekday: u8::try_from(day).unwrap(),
+	})
 }
 
 impl DateTimeRangeRaw {
@@ -214,19 +214,12 @@ impl DateTimeRangeRaw {
 	}
 }
 
-pub fn parse_from_file<P: AsRef<Path>>(path: Option<P>) -> Vec<Course> {
-	let document = if let Some(path) = path {
-		let file = File::open(path).unwrap();
-		Document::from_read(file).unwrap()
-	} else {
-		Document::from_read(std::io::stdin()).unwrap()
-	};
-
+fn parse_document(document: &Document, profile: &InstitutionProfile) -> Vec<Course> {
 	let mut courses = Vec::new();
 
-	for node in document.find(predicate::Class("PAGROUPDIVIDER")) {
+	for node in document.find(predicate::Class(profile.group_class)) {
 		let class = node.parent().unwrap().parent().unwrap();
-		let mut rows = class.find(predicate::Class("PSLEVEL3GRID"));
+		let mut rows = class.find(predicate::Class(profile.grid_class));
 
 		let mut head = rows.next().unwrap().find(Name("td")).map(|s| {
 			s.find(Name("span"))
@@ -237,10 +230,10 @@ pub fn parse_from_file<P: AsRef<Path>>(path: Option<P>) -> Vec<Course> {
 		let status = head.next().unwrap().parse().unwrap();
 
 		let title = node.text();
-		let mut title = title.split(" - ");
+		let mut title = title.split(profile.title_separator);
 		let code = title.next().unwrap().to_string();
 		let name = title.next().unwrap().to_string();
-		let cols = rows
+		let cells = rows
 			.next()
 			.unwrap()
 			.find(Name("td"))
@@ -249,55 +242,70 @@ pub fn parse_from_file<P: AsRef<Path>>(path: Option<P>) -> Vec<Course> {
 					.next()
 					.map_or_else(|| String::from("\u{a0}"), |s| s.text())
 			})
-			.array_chunks::<7>();
+			.collect::<Vec<_>>();
 
 		let mut prev = None::<Class>;
 
-		let classes = cols
-			.map(
-				|[_, section, component, time, location, instructor, start_end]| {
-					let section: Section = if section == "\u{a0}" {
-						prev.as_ref().unwrap().section
-					} else {
-						section.parse().unwrap()
-					};
-					let component = if component == "\u{a0}" {
-						prev.as_ref().unwrap().component
-					} else {
-						component.parse().unwrap()
-					};
-					let time: DateTimeRangeRaw = time.parse().unwrap();
-					let mut room = location.splitn(2, " (");
-					let address = room.next().unwrap().to_string();
-					let location = room.next().unwrap().to_string().replace(')', "");
-
-					let mut start_end = start_end.split(" - ");
-					let start = TZ.from_utc_datetime(
-						&NaiveDate::parse_from_str(start_end.next().unwrap(), "%m/%d/%Y")
-							.unwrap()
-							.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
-					) + chrono::Duration::hours(4);
-					let end = TZ.from_utc_datetime(
-						&NaiveDate::parse_from_str(start_end.next().unwrap(), "%m/%d/%Y")
-							.unwrap()
-							.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
-					) + chrono::Duration::hours(4);
-
-					let class = Class {
-						section,
-						component,
-						time: time.into_datetime_range(start),
-						location,
-						address,
-						instructor,
-
-						end,
-					};
-
-					prev = Some(class.clone());
-					class
-				},
-			)
+		let classes = cells
+			.chunks_exact(profile.columns)
+			.map(|row| {
+				let section = &row[profile.section_column];
+				let component = &row[profile.component_column];
+				let time = &row[profile.time_column];
+				let location = &row[profile.location_column];
+				let instructor = &row[profile.instructor_column];
+				let date_range = &row[profile.date_range_column];
+
+				let section: Section = if section == "\u{a0}" {
+					prev.as_ref().unwrap().section
+				} else {
+					section.parse().unwrap()
+				};
+				let component = if component == "\u{a0}" {
+					prev.as_ref().unwrap().component
+				} else {
+					component.parse().unwrap()
+				};
+				let time = parse_time_range(time, profile.weekdays).unwrap();
+				let mut room = location.splitn(2, profile.address_delimiter);
+				let address = room.next().unwrap().to_string();
+				let location = room.next().unwrap().to_string().replace(')', "");
+
+				let mut date_range = date_range.split(" - ");
+				// the scraped dates are calendar dates in the institution's own
+				// timezone, not UTC, so interpret them as local time directly;
+				// resolve_local covers an institution whose term boundary lands
+				// on a DST-ambiguous/nonexistent midnight instead of panicking
+				let start = resolve_local(
+					profile.timezone,
+					NaiveDate::parse_from_str(date_range.next().unwrap(), "%m/%d/%Y")
+						.unwrap()
+						.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+				)
+				.unwrap();
+				let end = resolve_local(
+					profile.timezone,
+					NaiveDate::parse_from_str(date_range.next().unwrap(), "%m/%d/%Y")
+						.unwrap()
+						.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+				)
+				.unwrap();
+
+				let class = Class {
+					section,
+					component,
+					time: time.into_datetime_range(start),
+					location,
+					address,
+					instructor: instructor.clone(),
+					instructor_email: None,
+
+					end,
+				};
+
+				prev = Some(class.clone());
+				class
+			})
 			.collect::<Vec<_>>();
 
 		courses.push(Course {
@@ -310,3 +318,20 @@ pub fn parse_from_file<P: AsRef<Path>>(path: Option<P>) -> Vec<Course> {
 
 	courses
 }
+
+pub fn parse_from_file<P: AsRef<Path>>(path: Option<P>, profile: &InstitutionProfile) -> Vec<Course> {
+	let document = if let Some(path) = path {
+		let file = File::open(path).unwrap();
+		Document::from_read(file).unwrap()
+	} else {
+		Document::from_read(std::io::stdin()).unwrap()
+	};
+
+	parse_document(&document, profile)
+}
+
+pub fn parse_from_buf(buf: &[u8], profile: &InstitutionProfile) -> Vec<Course> {
+	let document = Document::from_read(buf).unwrap();
+
+	parse_document(&document, profile)
+}
@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+/// An inclusive span of calendar dates during which no classes occur, e.g. a
+/// reading week or a single statutory holiday.
+#[derive(Debug, Clone, Copy)]
+pub struct ExcludedRange {
+	pub start: NaiveDate,
+	pub end: NaiveDate,
+}
+
+impl ExcludedRange {
+	#[must_use]
+	pub fn contains(&self, date: NaiveDate) -> bool {
+		(self.start..=self.end).contains(&date)
+	}
+}
+
+impl FromStr for ExcludedRange {
+	type Err = ();
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parts = s.splitn(2, "..");
+		let start = NaiveDate::parse_from_str(parts.next().ok_or(())?.trim(), "%Y-%m-%d")
+			.map_err(|_| ())?;
+		let end = parts
+			.next()
+			.map(|end| NaiveDate::parse_from_str(end.trim(), "%Y-%m-%d"))
+			.transpose()
+			.map_err(|_| ())?
+			.unwrap_or(start);
+
+		Ok(Self { start, end })
+	}
+}
+
+/// Parse a newline- or comma-separated list of excluded date ranges, e.g.
+/// `2025-02-17..2025-02-21` for a reading week or `2025-04-18` for a single
+/// statutory holiday.
+#[must_use]
+pub fn parse_exclusions(s: &str) -> Vec<ExcludedRange> {
+	s.split(['\n', ','])
+		.map(str::trim)
+		.filter(|s| !s.is_empty())
+		.filter_map(|s| s.parse().ok())
+		.collect()
+}
+
+/// Load exclusions from `arg`, treating it as a file path first and falling
+/// back to parsing it directly as an inline list.
+#[must_use]
+pub fn load_exclusions(arg: &str) -> Vec<ExcludedRange> {
+	std::fs::read_to_string(arg).map_or_else(|_| parse_exclusions(arg), |s| parse_exclusions(&s))
+}
+
+#[must_use]
+pub fn is_excluded(date: NaiveDate, ranges: &[ExcludedRange]) -> bool {
+	ranges.iter().any(|range| range.contains(date))
+}
@@ -0,0 +1,173 @@
+use chrono::{Datelike, Timelike};
+
+use crate::course::{Component, Course, Status};
+
+/// Whether instructor and room details are rendered, or collapsed into
+/// generic "Busy" blocks for sharing a timetable publicly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Privacy {
+	Public,
+	Private,
+}
+
+const DAY_START_HOUR: u32 = 8;
+const DAY_END_HOUR: u32 = 22;
+const DAY_LABELS: [&str; 5] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"];
+
+fn component_class(component: Component) -> &'static str {
+	match component {
+		Component::Lecture => "lec",
+		Component::Laboratory => "lab",
+		Component::Tutorial => "tut",
+	}
+}
+
+/// Escape `value` for use as HTML text content, so a scraped course code,
+/// location, or instructor name containing `&`/`<`/`>` can't break the
+/// surrounding markup.
+fn escape_html(value: &str) -> String {
+	value
+		.replace('&', "&amp;")
+		.replace('<', "&lt;")
+		.replace('>', "&gt;")
+}
+
+/// Render a standalone Monday-Friday HTML timetable, laid out by
+/// [`Class.time`](crate::course::Class::time) and tagged by
+/// [`Component`]. In [`Privacy::Public`] mode every block collapses to a
+/// generic "Busy" label; in [`Privacy::Private`] mode the course code,
+/// location, and instructor are shown.
+#[must_use]
+pub fn create_html(courses: Vec<Course>, privacy: Privacy) -> String {
+	let day_span = f64::from(DAY_END_HOUR - DAY_START_HOUR);
+	let mut days: [Vec<String>; 5] = Default::default();
+
+	for course in courses {
+		if matches!(course.status, Status::Waiting) {
+			continue;
+		}
+
+		for class in course.classes {
+			let weekday = class.time.start.weekday().num_days_from_monday() as usize;
+
+			let Some(column) = days.get_mut(weekday) else {
+				continue;
+			};
+
+			let start_hour =
+				f64::from(class.time.start.hour()) + f64::from(class.time.start.minute()) / 60.0;
+			let end_hour =
+				f64::from(class.time.end.hour()) + f64::from(class.time.end.minute()) / 60.0;
+
+			let top = (start_hour - f64::from(DAY_START_HOUR)) / day_span * 100.0;
+			let height = (end_hour - start_hour) / day_span * 100.0;
+
+			let label = match privacy {
+				Privacy::Public => "Busy".to_string(),
+				Privacy::Private => format!(
+					"{} ({})<br>{}<br>{}",
+					escape_html(&course.code),
+					class.component,
+					escape_html(&class.location),
+					escape_html(&class.instructor)
+				),
+			};
+
+			let class_name = match privacy {
+				Privacy::Public => "busy",
+				Privacy::Private => component_class(class.component),
+			};
+
+			column.push(format!(
+				r#"<div class="block {class_name}" style="top:{top:.2}%;height:{height:.2}%">{label}</div>"#,
+			));
+		}
+	}
+
+	let columns = days
+		.into_iter()
+		.zip(DAY_LABELS)
+		.map(|(blocks, label)| {
+			format!(
+				r#"<div class="day"><h2>{label}</h2><div class="slots">{}</div></div>"#,
+				blocks.join("")
+			)
+		})
+		.collect::<String>();
+
+	format!(
+		r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>University of Ottawa Timetable</title>
+<style>
+body {{ font-family: sans-serif; }}
+.week {{ display: flex; gap: 8px; }}
+.day {{ flex: 1; }}
+.day h2 {{ font-size: 14px; text-align: center; }}
+.slots {{ position: relative; height: 700px; border: 1px solid #ccc; }}
+.block {{ position: absolute; left: 0; right: 0; padding: 4px; font-size: 12px; overflow: hidden; box-sizing: border-box; color: #fff; }}
+.block.lec {{ background: #4c6ef5; }}
+.block.lab {{ background: #f76707; }}
+.block.tut {{ background: #2f9e44; }}
+.block.busy {{ background: #495057; }}
+</style>
+</head>
+<body>
+<div class="week">{columns}</div>
+</body>
+</html>
+"#
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::TimeZone;
+	use chrono_tz::America::Toronto;
+
+	use crate::course::{Class, Component as CourseComponent, Course, DateTimeRange, Status};
+
+	use super::{create_html, Privacy};
+
+	fn course() -> Course {
+		let start = Toronto.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+		let end = Toronto.with_ymd_and_hms(2026, 1, 5, 10, 0, 0).unwrap();
+
+		Course {
+			name: "Intro to Testing".to_string(),
+			code: "ABC1000".to_string(),
+			status: Status::Enrolled,
+			classes: vec![Class {
+				section: "A00".parse().unwrap(),
+				component: CourseComponent::Lecture,
+				time: DateTimeRange { start, end },
+				location: "Room <1>".to_string(),
+				address: "STE 0129".to_string(),
+				instructor: "A & B".to_string(),
+				instructor_email: None,
+				end,
+			}],
+		}
+	}
+
+	#[test]
+	fn public_mode_collapses_to_a_neutral_busy_block() {
+		let html = create_html(vec![course()], Privacy::Public);
+
+		assert!(html.contains(r#"class="block busy""#));
+		assert!(!html.contains("block lec"));
+		assert!(html.contains(">Busy<"));
+		assert!(!html.contains("ABC1000"));
+	}
+
+	#[test]
+	fn private_mode_escapes_scraped_html_special_characters() {
+		let html = create_html(vec![course()], Privacy::Private);
+
+		assert!(html.contains(r#"class="block lec""#));
+		assert!(html.contains("A &amp; B"));
+		assert!(html.contains("Room &lt;1&gt;"));
+	}
+}
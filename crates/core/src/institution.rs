@@ -0,0 +1,62 @@
+use chrono_tz::Tz;
+
+/// Parameterizes the PeopleSoft schedule scraper (`course::parse_from_file`,
+/// `course::parse_from_buf`) and `create_calendar` for a specific
+/// institution, so schools other than uOttawa - and timezones other than
+/// Eastern - can reuse the crate.
+#[derive(Debug, Clone, Copy)]
+pub struct InstitutionProfile {
+	/// CSS class marking the start of a course's block (`PAGROUPDIVIDER`).
+	pub group_class: &'static str,
+	/// CSS class marking a course's class-meeting grid (`PSLEVEL3GRID`).
+	pub grid_class: &'static str,
+	/// Number of `<td>` columns per class-meeting row.
+	pub columns: usize,
+	/// Index, within a row, of the section column.
+	pub section_column: usize,
+	/// Index, within a row, of the component (LEC/LAB/TUT) column.
+	pub component_column: usize,
+	/// Index, within a row, of the weekday/time-range column.
+	pub time_column: usize,
+	/// Index, within a row, of the location column.
+	pub location_column: usize,
+	/// Index, within a row, of the instructor column.
+	pub instructor_column: usize,
+	/// Index, within a row, of the term start/end date-range column.
+	pub date_range_column: usize,
+	/// Two-letter weekday labels, in the order they can appear in the time
+	/// column, e.g. `["Mo", "Tu", "We", "Th", "Fr"]`.
+	pub weekdays: &'static [&'static str],
+	/// Separator between a course's code and name in its title, e.g. `" - "`.
+	pub title_separator: &'static str,
+	/// Separator between a location's building address and room, e.g. `" ("`.
+	pub address_delimiter: &'static str,
+	/// The institution's timezone, used both to render events and to
+	/// interpret the otherwise-naive dates scraped from the page.
+	pub timezone: Tz,
+	/// The institution's display name, used as the generated calendar's
+	/// `NAME` property.
+	pub name: &'static str,
+	/// Suffix appended to a class's scraped address to build its `LOCATION`
+	/// property, e.g. `", Ottawa, ON, Canada"`.
+	pub location_suffix: &'static str,
+}
+
+/// The University of Ottawa's PeopleSoft "My Class Schedule" markup.
+pub const UOTTAWA: InstitutionProfile = InstitutionProfile {
+	group_class: "PAGROUPDIVIDER",
+	grid_class: "PSLEVEL3GRID",
+	columns: 7,
+	section_column: 1,
+	component_column: 2,
+	time_column: 3,
+	location_column: 4,
+	instructor_column: 5,
+	date_range_column: 6,
+	weekdays: &["Mo", "Tu", "We", "Th", "Fr"],
+	title_separator: " - ",
+	address_delimiter: " (",
+	timezone: chrono_tz::America::Toronto,
+	name: "University of Ottawa",
+	location_suffix: ", Ottawa, ON, Canada",
+};
@@ -1,19 +1,79 @@
-#![feature(iter_array_chunks)]
 #![warn(clippy::pedantic)]
 
+use buildings::BuildingRegistry;
 use course::Course;
+use exclusions::ExcludedRange;
+use chrono::TimeZone;
 use icalendar::{Calendar, CalendarDateTime, Component, Event, EventLike};
+use institution::InstitutionProfile;
 
+pub mod buildings;
+pub mod conflicts;
 pub mod course;
+pub mod exclusions;
+pub mod html;
+pub mod institution;
 
-pub const TZ: chrono_tz::Tz = chrono_tz::America::Toronto;
+/// Resolve a local wall-clock time that may fall in a daylight-saving gap
+/// or ambiguous fold, instead of requiring a single unambiguous instant.
+///
+/// Ambiguous times (fall-back) resolve to the earlier of the two
+/// instants. Times that don't exist (spring-forward gap) are pushed
+/// forward by an hour, which lands back in the post-transition offset for
+/// every DST rule currently in `chrono_tz` (all shift by a single hour).
+pub(crate) fn resolve_local(
+	tz: chrono_tz::Tz,
+	naive: chrono::NaiveDateTime,
+) -> Option<chrono::DateTime<chrono_tz::Tz>> {
+	match tz.from_local_datetime(&naive) {
+		chrono::LocalResult::Single(dt) | chrono::LocalResult::Ambiguous(dt, _) => Some(dt),
+		chrono::LocalResult::None => tz
+			.from_local_datetime(&(naive + chrono::Duration::hours(1)))
+			.single(),
+	}
+}
+
+/// Every weekly occurrence of a class, from its first meeting up to and
+/// including `until`.
+///
+/// Advances by 7 calendar days in local time and re-localizes, rather than
+/// adding a week to the absolute instant, so the wall-clock time of day is
+/// preserved across a daylight-saving transition (uOttawa's winter term
+/// always crosses the March change). Local times that are ambiguous or
+/// don't exist around that transition are resolved via [`resolve_local`]
+/// rather than dropping the rest of the sequence.
+pub(crate) fn weekly_occurrences(
+	start: chrono::DateTime<chrono_tz::Tz>,
+	until: chrono::DateTime<chrono_tz::Tz>,
+) -> impl Iterator<Item = chrono::DateTime<chrono_tz::Tz>> {
+	std::iter::successors(Some(start), move |prev| {
+		let tz = prev.timezone();
+		let next_local = prev.naive_local() + chrono::Duration::days(7);
+		let next = resolve_local(tz, next_local)?;
+
+		(next <= until).then_some(next)
+	})
+}
+
+/// Sanitize `value` for use inside an RFC 5545 quoted-string parameter
+/// value (e.g. `CN="..."`), which has no escape mechanism of its own and
+/// simply forbids the `"` character.
+pub(crate) fn quoted_param(value: &str) -> String {
+	value.replace('"', "'")
+}
 
 #[must_use]
-pub fn create_calendar(courses: Vec<Course>) -> Calendar {
+pub fn create_calendar(
+	courses: Vec<Course>,
+	exclusions: &[ExcludedRange],
+	buildings: &BuildingRegistry,
+	profile: &InstitutionProfile,
+) -> Calendar {
 	let mut calendar = Calendar::new();
+	let tz = profile.timezone;
 
-	calendar.name("University of Ottawa");
-	calendar.timezone(TZ.name());
+	calendar.name(profile.name);
+	calendar.timezone(tz.name());
 
 	for course in courses {
 		if matches!(course.status, course::Status::Waiting) {
@@ -34,17 +94,38 @@ pub fn create_calendar(courses: Vec<Course>) -> Calendar {
 			event
 				.starts(CalendarDateTime::WithTimezone {
 					date_time: start.naive_local(),
-					tzid: TZ.name().to_string(),
+					tzid: tz.name().to_string(),
 				})
 				.ends(CalendarDateTime::WithTimezone {
 					date_time: end.naive_local(),
-					tzid: TZ.name().to_string(),
+					tzid: tz.name().to_string(),
 				})
-				.location(&format!("{}, Ottawa, ON, Canada", class.address))
-				.description(&format!(
-					"Name: {} | Section: {} | Instructor: {}",
-					course.name, class.section, class.instructor
-				))
+				.location(&format!("{}{}", class.address, profile.location_suffix));
+
+			if let Some(building) = buildings.get_by_address(&class.address) {
+				event.add_property("GEO", building.geo());
+			}
+
+			event
+				.add_property("COMMENT", &course.name)
+				.add_property("COMMENT", &format!("Section {}", class.section));
+
+			// instructor as an RFC 5545 participant instead of free text; an
+			// ATTENDEE needs a usable CAL-ADDRESS, so fall back to a plain
+			// comment when no email was scraped rather than inventing one
+			if let Some(email) = &class.instructor_email {
+				event.add_property(
+					&format!(
+						"ATTENDEE;ROLE=CHAIR;CUTYPE=INDIVIDUAL;CN=\"{}\"",
+						quoted_param(&class.instructor)
+					),
+					format!("mailto:{email}"),
+				);
+			} else {
+				event.add_property("COMMENT", &format!("Instructor: {}", class.instructor));
+			}
+
+			event
 				// repeat weekly
 				.add_property(
 					"RRULE",
@@ -62,9 +143,93 @@ pub fn create_calendar(courses: Vec<Course>) -> Calendar {
 					"TRIGGER:-PT30M;ACTION=DISPLAY;DESCRIPTION=Reminder",
 				);
 
+			// skip reading week and statutory holidays, using the exact
+			// local time-of-day and TZID of DTSTART for each excluded date
+			let excluded_dates = weekly_occurrences(start, class.end)
+				.filter(|occurrence| exclusions::is_excluded(occurrence.date_naive(), exclusions))
+				.map(|occurrence| occurrence.naive_local().format("%Y%m%dT%H%M%S").to_string())
+				.collect::<Vec<_>>();
+
+			if !excluded_dates.is_empty() {
+				event.add_property(&format!("EXDATE;TZID={}", tz.name()), excluded_dates.join(","));
+			}
+
 			calendar.push(event);
 		}
 	}
 
 	calendar
 }
+
+#[cfg(test)]
+mod tests {
+	use chrono::TimeZone;
+	use chrono_tz::America::Toronto;
+
+	use crate::course::{Class, Component as CourseComponent, Course, DateTimeRange, Status};
+	use crate::institution::UOTTAWA;
+
+	use super::{create_calendar, weekly_occurrences, BuildingRegistry};
+
+	/// A weekly recurrence crossing the March "spring forward" transition
+	/// (clocks skip 2:00-3:00 AM) must keep its 9:00 AM wall-clock time and
+	/// must not drop any later occurrences.
+	#[test]
+	fn weekly_occurrences_survives_spring_forward() {
+		// 2026-03-01 is the Sunday before Canada's 2026-03-08 DST change.
+		let start = Toronto.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+		let until = Toronto.with_ymd_and_hms(2026, 3, 22, 9, 0, 0).unwrap();
+
+		let occurrences = weekly_occurrences(start, until).collect::<Vec<_>>();
+
+		assert_eq!(occurrences.len(), 4);
+
+		for occurrence in &occurrences {
+			assert_eq!(occurrence.naive_local().time(), start.naive_local().time());
+		}
+	}
+
+	fn course(instructor_email: Option<String>) -> Course {
+		let start = Toronto.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+		let end = Toronto.with_ymd_and_hms(2026, 1, 5, 10, 0, 0).unwrap();
+
+		Course {
+			name: "Intro to Testing".to_string(),
+			code: "ABC1000".to_string(),
+			status: Status::Enrolled,
+			classes: vec![Class {
+				section: "A00".parse().unwrap(),
+				component: CourseComponent::Lecture,
+				time: DateTimeRange { start, end },
+				location: "Room 1".to_string(),
+				address: "STE 0129".to_string(),
+				instructor: "Jane Doe".to_string(),
+				instructor_email,
+				end,
+			}],
+		}
+	}
+
+	#[test]
+	fn create_calendar_emits_attendee_only_with_a_known_email() {
+		let with_email = create_calendar(
+			vec![course(Some("jane@uottawa.ca".to_string()))],
+			&[],
+			&BuildingRegistry::default(),
+			&UOTTAWA,
+		)
+		.to_string();
+
+		assert!(with_email.contains("ATTENDEE"));
+		assert!(with_email.contains("mailto:jane@uottawa.ca"));
+	}
+
+	#[test]
+	fn create_calendar_falls_back_to_a_comment_without_an_email() {
+		let without_email =
+			create_calendar(vec![course(None)], &[], &BuildingRegistry::default(), &UOTTAWA).to_string();
+
+		assert!(!without_email.contains("ATTENDEE"));
+		assert!(without_email.contains("Instructor: Jane Doe"));
+	}
+}
@@ -5,8 +5,10 @@ use wasm_bindgen::prelude::wasm_bindgen;
 #[wasm_bindgen(js_name = fromHtml)]
 #[must_use]
 pub fn from_html(html: &[u8]) -> String {
-	let courses = uo2ics_core::course::parse_from_buf(html);
-	let calendar = uo2ics_core::create_calendar(courses);
+	let profile = uo2ics_core::institution::UOTTAWA;
+	let courses = uo2ics_core::course::parse_from_buf(html, &profile);
+	let buildings = uo2ics_core::buildings::BuildingRegistry::default();
+	let calendar = uo2ics_core::create_calendar(courses, &[], &buildings, &profile);
 
 	calendar.to_string()
 }